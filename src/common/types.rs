@@ -0,0 +1,189 @@
+/// State of an issue across providers.
+///
+/// Providers are inconsistent about casing and wording ("Open", "OPEN",
+/// "opened"), so the `Deserialize` impl lowercases the incoming string before
+/// matching. Serialization always emits the canonical lowercase form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[napi]
+pub enum IssueState {
+    Open,
+    Closed,
+}
+
+impl Serialize for IssueState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            IssueState::Open => "open",
+            IssueState::Closed => "closed",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for IssueState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct IssueStateVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for IssueStateVisitor {
+            type Value = IssueState;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an issue state string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<IssueState, E>
+            where
+                E: serde::de::Error,
+            {
+                match value.to_lowercase().as_str() {
+                    "open" | "opened" | "reopened" => Ok(IssueState::Open),
+                    "closed" => Ok(IssueState::Closed),
+                    other => Err(E::invalid_value(
+                        serde::de::Unexpected::Str(other),
+                        &self,
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(IssueStateVisitor)
+    }
+}
+
+/// State of a merge/pull request across providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[napi]
+pub enum MergeRequestState {
+    Opened,
+    Closed,
+    Merged,
+    Locked,
+}
+
+impl Serialize for MergeRequestState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            MergeRequestState::Opened => "opened",
+            MergeRequestState::Closed => "closed",
+            MergeRequestState::Merged => "merged",
+            MergeRequestState::Locked => "locked",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeRequestState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct MergeRequestStateVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for MergeRequestStateVisitor {
+            type Value = MergeRequestState;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a merge request state string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<MergeRequestState, E>
+            where
+                E: serde::de::Error,
+            {
+                match value.to_lowercase().as_str() {
+                    "open" | "opened" | "reopened" => Ok(MergeRequestState::Opened),
+                    "closed" => Ok(MergeRequestState::Closed),
+                    "merged" => Ok(MergeRequestState::Merged),
+                    "locked" => Ok(MergeRequestState::Locked),
+                    other => Err(E::invalid_value(
+                        serde::de::Unexpected::Str(other),
+                        &self,
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(MergeRequestStateVisitor)
+    }
+}
+
+/// Type of actor that triggered a bridged event.
+///
+/// Maps common synonyms ("organization" → `Org`) and is case-insensitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[napi]
+pub enum ActorType {
+    User,
+    Org,
+    Bot,
+}
+
+impl Serialize for ActorType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            ActorType::User => "user",
+            ActorType::Org => "org",
+            ActorType::Bot => "bot",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ActorType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ActorTypeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ActorTypeVisitor {
+            type Value = ActorType;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an actor type string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<ActorType, E>
+            where
+                E: serde::de::Error,
+            {
+                match value.to_lowercase().as_str() {
+                    "user" => Ok(ActorType::User),
+                    "org" | "organization" => Ok(ActorType::Org),
+                    "bot" => Ok(ActorType::Bot),
+                    other => Err(E::invalid_value(
+                        serde::de::Unexpected::Str(other),
+                        &self,
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(ActorTypeVisitor)
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct MinimalActor {
+    pub id: u32,
+    pub login: String,
+    #[napi(js_name = "display_name")]
+    pub display_name: String,
+    #[napi(js_name = "avatar_url")]
+    pub avatar_url: String,
+    #[napi(js_name = "html_url")]
+    pub html_url: String,
+    #[serde(rename = "actor_type")]
+    #[napi(js_name = "actor_type")]
+    pub actor_type: ActorType,
+}
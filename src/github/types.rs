@@ -7,6 +7,33 @@ pub struct MinimalGitHubRepo {
     #[napi(js_name = "html_url")]
     pub html_url: String,
     pub description: Option<String>,
+    pub private: bool,
+    #[napi(js_name = "default_branch")]
+    pub default_branch: String,
+    pub visibility: String,
+    pub owner: MinimalGitHubOwner,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct MinimalGitHubOwner {
+    pub login: String,
+    #[serde(rename = "type")]
+    #[napi(js_name = "type")]
+    pub owner_type: ActorType,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct MinimalGitHubPullRequest {
+    pub id: u32,
+    pub number: u32,
+    pub title: String,
+    #[napi(js_name = "html_url")]
+    pub html_url: String,
+    pub state: MergeRequestState,
+    pub draft: bool,
+    pub merged: bool,
 }
 
 #[derive(Serialize, Debug, Deserialize)]
@@ -25,6 +52,23 @@ pub struct GitHubIssueMessageBodyRepo {
     pub id: u32,
     pub name: String,
     pub url: String,
+    pub private: bool,
+    #[napi(js_name = "default_branch")]
+    pub default_branch: String,
+    pub visibility: String,
+    pub owner: MinimalGitHubOwner,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitHubIssueMessageBodyPullRequest {
+    pub id: u32,
+    pub number: u32,
+    pub title: String,
+    pub url: String,
+    pub state: MergeRequestState,
+    pub draft: bool,
+    pub merged: bool,
 }
 
 #[derive(Serialize, Debug, Deserialize)]
@@ -34,6 +78,7 @@ pub struct GitHubIssueMessageBodyIssue {
     pub number: u32,
     pub title: String,
     pub url: String,
+    pub state: IssueState,
 }
 
 #[derive(Serialize, Debug, Deserialize)]
@@ -55,6 +100,25 @@ pub struct GitHubIssueMessageBody {
     #[serde(rename = "gk.bridgeas.github.repo")]
     #[napi(js_name = "gk.bridgeas.github.repo")]
     pub repo: GitHubIssueMessageBodyRepo,
+    #[serde(rename = "gk.bridgeas.github.actor")]
+    #[napi(js_name = "gk.bridgeas.github.actor")]
+    pub actor: MinimalActor,
+    #[napi(js_name = "external_url")]
+    pub external_url: String,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitHubPullRequestMessageBody {
+    #[serde(rename = "gk.bridgeas.github.pull_request")]
+    #[napi(js_name = "gk.bridgeas.github.pull_request")]
+    pub pull_request: GitHubIssueMessageBodyPullRequest,
+    #[serde(rename = "gk.bridgeas.github.repo")]
+    #[napi(js_name = "gk.bridgeas.github.repo")]
+    pub repo: GitHubIssueMessageBodyRepo,
+    #[serde(rename = "gk.bridgeas.github.actor")]
+    #[napi(js_name = "gk.bridgeas.github.actor")]
+    pub actor: MinimalActor,
     #[napi(js_name = "external_url")]
     pub external_url: String,
 }
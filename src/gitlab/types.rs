@@ -0,0 +1,101 @@
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct MinimalGitLabProject {
+    pub id: u32,
+    #[napi(js_name = "path_with_namespace")]
+    pub path_with_namespace: String,
+    #[napi(js_name = "web_url")]
+    pub web_url: String,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct MinimalGitLabIssue {
+    pub id: u32,
+    pub iid: u32,
+    pub title: String,
+    #[napi(js_name = "web_url")]
+    pub web_url: String,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct MinimalGitLabMergeRequest {
+    pub id: u32,
+    pub iid: u32,
+    pub title: String,
+    pub state: MergeRequestState,
+    #[napi(js_name = "web_url")]
+    pub web_url: String,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitLabMessageBodyProject {
+    pub id: u32,
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitLabMessageBodyIssue {
+    pub id: u32,
+    pub iid: u32,
+    pub title: String,
+    pub url: String,
+    pub state: IssueState,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitLabMessageBodyMergeRequest {
+    pub id: u32,
+    pub iid: u32,
+    pub title: String,
+    pub state: MergeRequestState,
+    pub url: String,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitLabIssueMessageBody {
+    #[serde(rename = "gk.bridgeas.gitlab.issue")]
+    #[napi(js_name = "gk.bridgeas.gitlab.issue")]
+    pub issue: GitLabMessageBodyIssue,
+    #[serde(rename = "gk.bridgeas.gitlab.project")]
+    #[napi(js_name = "gk.bridgeas.gitlab.project")]
+    pub project: GitLabMessageBodyProject,
+    #[serde(rename = "gk.bridgeas.gitlab.actor")]
+    #[napi(js_name = "gk.bridgeas.gitlab.actor")]
+    pub actor: MinimalActor,
+    #[napi(js_name = "external_url")]
+    pub external_url: String,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitLabMergeRequestMessageBody {
+    #[serde(rename = "gk.bridgeas.gitlab.merge_request")]
+    #[napi(js_name = "gk.bridgeas.gitlab.merge_request")]
+    pub merge_request: GitLabMessageBodyMergeRequest,
+    #[serde(rename = "gk.bridgeas.gitlab.project")]
+    #[napi(js_name = "gk.bridgeas.gitlab.project")]
+    pub project: GitLabMessageBodyProject,
+    #[serde(rename = "gk.bridgeas.gitlab.actor")]
+    #[napi(js_name = "gk.bridgeas.gitlab.actor")]
+    pub actor: MinimalActor,
+    #[napi(js_name = "external_url")]
+    pub external_url: String,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+#[napi(object)]
+pub struct GitLabProjectMessageBody {
+    #[serde(rename = "gk.bridgeas.gitlab.project")]
+    #[napi(js_name = "gk.bridgeas.gitlab.project")]
+    pub project: GitLabMessageBodyProject,
+    #[napi(js_name = "external_url")]
+    pub external_url: String,
+}
@@ -49,6 +49,9 @@ pub struct JiraIssueMessageBody {
     #[serde(rename = "gk.bridgeas.jira.project")]
     #[napi(js_name = "gk.bridgeas.jira.project")]
     pub jira_project: JiraIssueSimpleItem,
+    #[serde(rename = "gk.bridgeas.jira.actor")]
+    #[napi(js_name = "gk.bridgeas.jira.actor")]
+    pub actor: MinimalActor,
     #[napi(js_name = "external_url")]
     pub external_url: String,
 }
@@ -0,0 +1,163 @@
+/// A timestamp parsed from a provider webhook.
+///
+/// Providers are wildly inconsistent about date formats — GitHub emits
+/// RFC 3339, while GitLab variously emits `"%Y-%m-%d %H:%M:%S %Z"` or
+/// `"%Y-%m-%d %H:%M:%S %z"` depending on the event. `HookDate` accepts any
+/// of them on deserialization and always serializes back as RFC 3339.
+#[derive(Debug, Clone)]
+pub struct HookDate(pub DateTime<Utc>);
+
+impl Serialize for HookDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HookDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        if let Ok(dt) = DateTime::parse_from_rfc3339(&raw) {
+            return Ok(HookDate(dt.with_timezone(&Utc)));
+        }
+        // Numeric-offset form, e.g. "2021-08-01 12:00:00 +0000".
+        if let Ok(dt) = DateTime::parse_from_str(&raw, "%Y-%m-%d %H:%M:%S %z") {
+            return Ok(HookDate(dt.with_timezone(&Utc)));
+        }
+        // Named-zone form, e.g. "2021-08-01 12:00:00 UTC". `%Z` only consumes the
+        // abbreviation without yielding an offset, so resolve it ourselves.
+        if let Some((datetime, zone)) = raw.rsplit_once(' ') {
+            if let (Ok(naive), Some(offset)) = (
+                NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S"),
+                named_zone_offset(zone),
+            ) {
+                if let Some(local) = FixedOffset::east_opt(offset)
+                    .and_then(|tz| tz.from_local_datetime(&naive).single())
+                {
+                    return Ok(HookDate(local.with_timezone(&Utc)));
+                }
+            }
+        }
+        Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(&raw),
+            &"an RFC 3339 or space-separated provider timestamp",
+        ))
+    }
+}
+
+/// Resolve a timezone abbreviation to its offset east of UTC, in seconds.
+///
+/// Providers emit named zones rather than numeric offsets in some payloads;
+/// only the handful actually seen in the wild are mapped, everything else
+/// falls through to a deserialization error.
+fn named_zone_offset(zone: &str) -> Option<i32> {
+    match zone {
+        "UTC" | "GMT" | "Z" => Some(0),
+        _ => None,
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitHubIssuesEvent {
+    pub action: String,
+    pub issue: MinimalGitHubIssue,
+    pub repository: MinimalGitHubRepo,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitHubPushEvent {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub before: String,
+    pub after: String,
+    pub repository: MinimalGitHubRepo,
+    pub commits: Vec<GitHubPushCommit>,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitHubPushCommit {
+    pub id: String,
+    pub message: String,
+    pub timestamp: HookDate,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitLabIssueEvent {
+    pub object_kind: String,
+    pub project: MinimalGitLabProject,
+    pub object_attributes: GitLabIssueAttributes,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitLabIssueAttributes {
+    pub id: u32,
+    pub iid: u32,
+    pub title: String,
+    #[serde(rename = "url")]
+    pub web_url: String,
+    pub created_at: HookDate,
+    pub updated_at: HookDate,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitLabMergeRequestEvent {
+    pub object_kind: String,
+    pub project: MinimalGitLabProject,
+    pub object_attributes: GitLabMergeRequestAttributes,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitLabMergeRequestAttributes {
+    pub id: u32,
+    pub iid: u32,
+    pub title: String,
+    pub state: String,
+    #[serde(rename = "url")]
+    pub web_url: String,
+    pub created_at: HookDate,
+    pub updated_at: HookDate,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitLabNoteEvent {
+    pub object_kind: String,
+    pub project: MinimalGitLabProject,
+    pub object_attributes: GitLabNoteAttributes,
+}
+
+#[derive(Serialize, Debug, Deserialize)]
+pub struct GitLabNoteAttributes {
+    pub id: u32,
+    pub note: String,
+    #[serde(rename = "url")]
+    pub web_url: String,
+    pub created_at: HookDate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hook_date_parses_named_timezone() {
+        let parsed: HookDate =
+            serde_json::from_str("\"2021-08-01 12:00:00 UTC\"").expect("named tz should parse");
+        assert_eq!(parsed.0, Utc.with_ymd_and_hms(2021, 8, 1, 12, 0, 0).unwrap());
+        // Round-trips back out as RFC 3339.
+        let out = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(out, "\"2021-08-01T12:00:00Z\"");
+    }
+
+    #[test]
+    fn hook_date_parses_numeric_offset_and_rfc3339() {
+        let numeric: HookDate =
+            serde_json::from_str("\"2021-08-01 12:00:00 +0000\"").unwrap();
+        let rfc: HookDate = serde_json::from_str("\"2021-08-01T12:00:00Z\"").unwrap();
+        assert_eq!(numeric.0, rfc.0);
+    }
+}